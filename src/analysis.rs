@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use crate::is_top_word;
+
+/// Count how often each word appears in the given text.
+///
+/// The text is tokenized on runs of non-alphabetic characters and every token
+/// is lowercased before counting, so punctuation and capitalization are
+/// ignored. The result is ordered by descending count, with ties broken
+/// alphabetically.
+///
+/// # Example
+///
+/// ```
+/// use top_english_words::word_frequencies;
+///
+/// let counts = word_frequencies("The cat sat. The cat ran!");
+/// assert_eq!(counts.first().unwrap().0, "cat");
+/// ```
+#[inline]
+pub fn word_frequencies(text: &str) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+
+    for token in text.split(|c: char| !c.is_alphabetic()) {
+        if token.is_empty() {
+            continue;
+        }
+        *counts.entry(token.to_lowercase()).or_insert(0) += 1;
+    }
+
+    let mut frequencies: Vec<(String, usize)> = counts.into_iter().collect();
+    frequencies.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    frequencies
+}
+
+/// Get the `n` most frequent words in the given text, each tagged with its rank
+/// in the list of top English words.
+///
+/// The third element of each tuple is the word's rank as reported by
+/// [is_top_word](crate::is_top_word), or [None] if the word is not in the list.
+/// This lets callers distinguish common vocabulary from rare words while
+/// profiling arbitrary text.
+///
+/// # Example
+///
+/// ```
+/// use top_english_words::top_n_frequent;
+///
+/// let top = top_n_frequent("the the the rare rare word", 2);
+/// ```
+#[inline]
+pub fn top_n_frequent(text: &str, n: usize) -> Vec<(String, usize, Option<usize>)> {
+    word_frequencies(text)
+        .into_iter()
+        .take(n)
+        .map(|(word, count)| {
+            let rank = is_top_word(&word);
+            (word, count, rank)
+        })
+        .collect()
+}
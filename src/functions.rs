@@ -1,8 +1,24 @@
+use std::collections::HashMap;
 use std::ops::{Bound, RangeBounds};
+use std::sync::OnceLock;
 
 use crate::word_list::WORD_LIST;
 use crate::NUM_WORDS;
 
+/// Lazily-built alphabetically sorted view over [WORD_LIST].
+///
+/// [WORD_LIST] is rank-ordered, so the prefix and binary-search based lookups
+/// keep their own sorted index. It is built once on first use and shared for
+/// the lifetime of the program.
+fn sorted_index() -> &'static [&'static str] {
+    static SORTED: OnceLock<Vec<&'static str>> = OnceLock::new();
+    SORTED.get_or_init(|| {
+        let mut words = WORD_LIST.to_vec();
+        words.sort_unstable();
+        words
+    })
+}
+
 /// Get all the words from the list of top English words.
 ///
 /// The words will be ordered by their rank in the list.
@@ -118,14 +134,279 @@ where
     Some(T::from(WORD_LIST.get(position)?))
 }
 
+/// A Levenshtein automaton for a fixed query string and edit distance.
+///
+/// The automaton is a deterministic accepter over input characters: its state
+/// is the row of reachable edit distances across the query positions. Feeding a
+/// character advances every position at once; once every distance in the row
+/// exceeds the allowed maximum the state is "dead" and the word can be rejected
+/// without examining the rest of its characters.
+struct LevAutomaton {
+    query: Vec<char>,
+    max_distance: u8,
+}
+
+impl LevAutomaton {
+    /// Run `word` through the automaton, returning its edit distance to the
+    /// query if that distance is within `max_distance`.
+    fn distance(&self, word: &str) -> Option<u8> {
+        let max = u32::from(self.max_distance);
+        let n = self.query.len();
+
+        // `row[j]` is the edit distance between the consumed prefix of `word`
+        // and the first `j` characters of the query. We only ever keep one row.
+        let mut row: Vec<u32> = (0..=n as u32).collect();
+
+        for c in word.chars() {
+            let mut prev_diag = row[0];
+            row[0] += 1;
+            let mut best = row[0];
+
+            for j in 0..n {
+                let cost = u32::from(self.query[j] != c);
+                let value = (prev_diag + cost).min(row[j] + 1).min(row[j + 1] + 1);
+                prev_diag = row[j + 1];
+                row[j + 1] = value;
+                best = best.min(value);
+            }
+
+            // Every reachable position is already beyond the budget.
+            if best > max {
+                return None;
+            }
+        }
+
+        let distance = row[n];
+        (distance <= max).then_some(distance as u8)
+    }
+}
+
+/// Builder that caches the edit-distance parameter so the automaton for any
+/// query can be constructed cheaply, mirroring MeiliSearch's
+/// `LevenshteinAutomatonBuilder`.
+struct LevAutomatonBuilder {
+    max_distance: u8,
+}
+
+impl LevAutomatonBuilder {
+    fn build(&self, query: &str) -> LevAutomaton {
+        LevAutomaton {
+            query: query.chars().collect(),
+            max_distance: self.max_distance,
+        }
+    }
+}
+
+/// Fetch the cached builder for the given distance, building it once per
+/// distance so repeated queries reuse it.
+fn lev_builder(max_distance: u8) -> &'static LevAutomatonBuilder {
+    static D0: OnceLock<LevAutomatonBuilder> = OnceLock::new();
+    static D1: OnceLock<LevAutomatonBuilder> = OnceLock::new();
+    static D2: OnceLock<LevAutomatonBuilder> = OnceLock::new();
+
+    // Distances above 2 are treated as 2.
+    let max_distance = max_distance.min(2);
+    let cell = match max_distance {
+        0 => &D0,
+        1 => &D1,
+        _ => &D2,
+    };
+
+    cell.get_or_init(|| LevAutomatonBuilder { max_distance })
+}
+
+/// Find every top word within a bounded Levenshtein distance of `query`.
+///
+/// Each returned word is paired with its actual edit distance to the query.
+/// Results are sorted by distance and then by rank, so the closest and most
+/// common matches come first. `max_distance` is expected to be `1` or `2`; a
+/// larger value is treated as `2`.
+///
+/// This powers spell-correction and "did you mean" lookups. The Levenshtein
+/// automaton for the query is built once and every word is streamed through it,
+/// rejecting non-matches early instead of filling a fresh distance matrix per
+/// word. Words are compared by `char`, so queries containing non-ASCII
+/// characters behave correctly.
+///
+/// # Example
+///
+/// ```
+/// use top_english_words::find_similar;
+///
+/// let suggestions = find_similar::<String>("wrold", 1);
+/// ```
+#[inline]
+pub fn find_similar<T>(query: &str, max_distance: u8) -> Vec<(T, u8)>
+where
+    T: From<&'static str>,
+{
+    let automaton = lev_builder(max_distance).build(query);
+
+    let mut matches: Vec<(usize, u8)> = WORD_LIST
+        .iter()
+        .enumerate()
+        .filter_map(|(rank, word)| automaton.distance(word).map(|d| (rank, d)))
+        .collect();
+
+    // Closest first, falling back to the more frequent (lower rank) word.
+    matches.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+
+    matches
+        .into_iter()
+        .map(|(rank, distance)| (T::from(WORD_LIST[rank]), distance))
+        .collect()
+}
+
+/// Get every word beginning with the given prefix, together with a "completion
+/// mask" describing which letters may legally follow it.
+///
+/// The returned vector contains every word in the list that starts with
+/// `prefix`, ordered alphabetically. The accompanying `u32` is a bit mask in
+/// which bit `i` is set if and only if some matching word has the character
+/// `'a' + i` at position `prefix.len()`; this lets a text-entry UI disable the
+/// keys that cannot continue the current prefix. A word exactly equal to the
+/// prefix contributes to the vector but not to the mask.
+///
+/// Matches are located with two binary searches over the alphabetical index, so
+/// this is cheap even though the list is stored in rank order.
+///
+/// # Example
+///
+/// ```
+/// use top_english_words::complete_prefix;
+///
+/// let (words, mask) = complete_prefix::<String>("th");
+/// ```
+#[inline]
+pub fn complete_prefix<T>(prefix: &str) -> (Vec<T>, u32)
+where
+    T: From<&'static str>,
+{
+    let index = sorted_index();
+
+    // Lower bound: first word that is not ordered before the prefix.
+    let start = index.partition_point(|w| *w < prefix);
+    // Upper bound: words beginning with the prefix are contiguous and compare
+    // greater-or-equal to it, so the matching slice ends where `starts_with`
+    // stops holding.
+    let end = start + index[start..].partition_point(|w| w.starts_with(prefix));
+
+    let mut mask = 0u32;
+    let mut words = Vec::with_capacity(end - start);
+
+    for &word in &index[start..end] {
+        if let Some(&byte) = word.as_bytes().get(prefix.len()) {
+            if byte.is_ascii_lowercase() {
+                mask |= 1 << (byte - b'a');
+            }
+        }
+        words.push(T::from(word));
+    }
+
+    (words, mask)
+}
+
+/// Search the list for the words that best match `query`, most useful first.
+///
+/// Each word is scored by combining how well it matches the query with how
+/// common it is: an exact prefix match outweighs a substring match, which in
+/// turn outweighs a fuzzy (edit-distance) match. That base component is
+/// multiplied by a frequency factor of `1.0 - index / NUM_WORDS`, so that among
+/// equally good matches the more frequent word ranks higher. Only words with a
+/// nonzero match component are returned, sorted by descending score.
+///
+/// This gives autocomplete and menu UIs a single ranked entry point instead of
+/// filtering and sorting by hand.
+///
+/// # Example
+///
+/// ```
+/// use top_english_words::search;
+///
+/// let ranked = search::<String>("wor");
+/// ```
+#[inline]
+pub fn search<T>(query: &str) -> Vec<(T, f64)>
+where
+    T: From<&'static str>,
+{
+    const PREFIX_WEIGHT: f64 = 3.0;
+    const SUBSTRING_WEIGHT: f64 = 2.0;
+    const FUZZY_WEIGHT: f64 = 1.0;
+
+    let automaton = lev_builder(2).build(query);
+
+    let mut scored: Vec<(usize, f64)> = WORD_LIST
+        .iter()
+        .enumerate()
+        .filter_map(|(index, word)| {
+            let base = if word.starts_with(query) {
+                PREFIX_WEIGHT
+            } else if word.contains(query) {
+                SUBSTRING_WEIGHT
+            } else if automaton.distance(word).is_some() {
+                FUZZY_WEIGHT
+            } else {
+                return None;
+            };
+
+            let frequency = 1.0 - index as f64 / NUM_WORDS as f64;
+            Some((index, base * frequency))
+        })
+        .collect();
+
+    // Highest score first; fall back to rank so the ordering is stable.
+    scored.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.0.cmp(&b.0))
+    });
+
+    scored
+        .into_iter()
+        .map(|(index, score)| (T::from(WORD_LIST[index]), score))
+        .collect()
+}
+
+/// Lazily-built map from each word to its rank in [WORD_LIST].
+///
+/// Built once on first use so that membership and rank lookups are O(1) for
+/// callers that perform many tests, such as tokenizers and filters.
+fn rank_index() -> &'static HashMap<&'static str, usize> {
+    static RANKS: OnceLock<HashMap<&'static str, usize>> = OnceLock::new();
+    RANKS.get_or_init(|| {
+        WORD_LIST
+            .iter()
+            .enumerate()
+            .map(|(rank, word)| (*word, rank))
+            .collect()
+    })
+}
+
 /// Check if the given word is in the list of top English words.
 ///
 /// If the word is in the list, return its index.
 /// Note that the list is sorted by how frequently their used. Lower indices
 /// mean that a word is used more frequently than another.
 ///
+/// The first call builds a hash map from word to rank; every subsequent lookup
+/// runs in O(1) rather than scanning the list.
+///
 /// If the word is not present in the list, this function returns [None].
 #[inline]
 pub fn is_top_word(word: &str) -> Option<usize> {
-    WORD_LIST.iter().position(|w| *w == word)
+    rank_index().get(word).copied()
+}
+
+/// Check if the given word is in the list of top English words using binary
+/// search over the alphabetical index.
+///
+/// Unlike [is_top_word] this builds no hash map, trading the rank result for
+/// deterministic memory use and zero hashing overhead. It is a good fit for
+/// environments that only need membership tests.
+#[inline]
+pub fn is_top_word_sorted(word: &str) -> bool {
+    sorted_index()
+        .binary_search_by(|probe| (**probe).cmp(word))
+        .is_ok()
 }